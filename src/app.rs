@@ -1,23 +1,104 @@
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+
 use crate::util::*;
 use crate::console::Console;
-use crate::command_handler::*;
+use crate::cmd::*;
+use crate::options::Options;
+use crate::system::System;
+use crate::process;
 
 pub struct App {
     pub mode: Mode,
     pub processes_sort_by: SortBy,
     pub processes_sort_direction: SortDirection,
     pub size: tui::layout::Rect,
-    pub console: Console
+    pub console: Console,
+    pub system: System,
+    // The selected row is tracked by pid rather than table position, since the
+    // table is re-sorted by the current sort column on every render tick.
+    pub selected_pid: Option<i32>,
+    pub process_scroll_offset: usize,
+    pub temperature_unit: TemperatureUnit,
+    pub per_core_cpu: bool,
+    // Shared with the polling thread, which re-reads it every tick so
+    // `set refresh <ms>` takes effect without a restart.
+    pub refresh_ms: Arc<AtomicU64>,
 }
 
 impl App {
-    pub fn new() -> App {
+    pub fn new(options: &Options, refresh_ms: Arc<AtomicU64>, initial_size: u16) -> App {
         App {
             mode: Mode::Main,
-            processes_sort_by: SortBy::CPU,
-            processes_sort_direction: SortDirection::DESC,
+            processes_sort_by: options.sort_by,
+            processes_sort_direction: options.sort_direction,
             size: tui::layout::Rect::new(0, 0, 0, 0),
-            console: Console::new()
+            console: Console::new(),
+            system: System::new(initial_size),
+            selected_pid: None,
+            process_scroll_offset: 0,
+            temperature_unit: options.temperature_unit,
+            per_core_cpu: options.per_core_cpu,
+            refresh_ms,
+        }
+    }
+
+    // The process table's current sort order, by pid. Shared with the
+    // renderer so the selection and the displayed rows never disagree.
+    fn sorted_pids(&self) -> Vec<i32> {
+        crate::system::sorted_processes(&self.system.processes, self.processes_sort_by, self.processes_sort_direction)
+            .into_iter()
+            .map(|p| p.pid)
+            .collect()
+    }
+
+    // Moves the process selection by `delta` rows in the current sort order,
+    // clamped to the process list bounds, and keeps the scroll offset
+    // following it within `viewport_height`.
+    pub fn move_process_selection(&mut self, delta: i64, viewport_height: usize) {
+        let pids = self.sorted_pids();
+
+        if pids.is_empty() {
+            self.selected_pid = None;
+            self.process_scroll_offset = 0;
+            return;
+        }
+
+        let current_index = self.selected_pid
+            .and_then(|pid| pids.iter().position(|&p| p == pid))
+            .unwrap_or(0);
+
+        let new_index = (current_index as i64 + delta)
+            .max(0)
+            .min(pids.len() as i64 - 1) as usize;
+
+        self.selected_pid = Some(pids[new_index]);
+        self.scroll_to_selection(new_index, viewport_height);
+    }
+
+    pub fn select_first_process(&mut self) {
+        self.selected_pid = self.sorted_pids().into_iter().next();
+        self.process_scroll_offset = 0;
+    }
+
+    pub fn select_last_process(&mut self, viewport_height: usize) {
+        let pids = self.sorted_pids();
+
+        if let Some(&last_pid) = pids.last() {
+            self.selected_pid = Some(last_pid);
+            self.scroll_to_selection(pids.len() - 1, viewport_height);
+        }
+    }
+
+    fn scroll_to_selection(&mut self, index: usize, viewport_height: usize) {
+        if viewport_height == 0 {
+            return;
+        }
+
+        if index < self.process_scroll_offset {
+            self.process_scroll_offset = index;
+        } else if index >= self.process_scroll_offset + viewport_height {
+            self.process_scroll_offset = index - viewport_height + 1;
         }
     }
 
@@ -38,7 +119,7 @@ impl App {
         let input = self.console.clear_input();
 
         match handle_cmd(nom::types::CompleteStr(&input)) {
-            Ok((_, cmd)) => self.console.write(format!("{:?}", cmd)),
+            Ok((_, cmd)) => self.run_cmd(cmd),
             Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
                 if let nom::Context::Code(_, nom::ErrorKind::Custom(cmd_err)) = e {
                     self.console.write(cmd_err.display());
@@ -49,4 +130,29 @@ impl App {
             _ => {}
         }
     }
+
+    fn run_cmd(&mut self, cmd: Cmd) {
+        match cmd {
+            Cmd::KillProcess(pid, signal) => self.kill_process(pid, signal),
+            Cmd::SetTemperatureUnit(unit) => self.temperature_unit = unit,
+            Cmd::SetRefresh(ms) => {
+                let ms = ms.max(crate::options::MIN_REFRESH_MS);
+                self.refresh_ms.store(ms, std::sync::atomic::Ordering::Relaxed);
+                self.console.write(format!("refresh interval set to {}ms", ms));
+            }
+            Cmd::SetCpuMode(per_core) => self.per_core_cpu = per_core,
+        }
+    }
+
+    fn kill_process(&mut self, pid: i32, signal: Option<i32>) {
+        if !self.system.has_process(pid) {
+            self.console.write(CmdError::UnknownPid(pid).display());
+            return;
+        }
+
+        match process::kill_process(pid, signal) {
+            Ok(()) => self.console.write(format!("sent signal to process {}", pid)),
+            Err(reason) => self.console.write(CmdError::KillFailed(reason).display()),
+        }
+    }
 }
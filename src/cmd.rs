@@ -0,0 +1,34 @@
+use nom::types::CompleteStr;
+
+use crate::parser::parse_cmd;
+use crate::util::TemperatureUnit;
+
+// A successfully parsed console command
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cmd {
+    KillProcess(i32, Option<i32>),
+    SetTemperatureUnit(TemperatureUnit),
+    SetRefresh(u64),
+    SetCpuMode(bool),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CmdError {
+    ParseErr,
+    UnknownPid(i32),
+    KillFailed(String),
+}
+
+impl CmdError {
+    pub fn display(&self) -> String {
+        match self {
+            CmdError::ParseErr => "error: could not parse command".to_string(),
+            CmdError::UnknownPid(pid) => format!("error: no tracked process with pid {}", pid),
+            CmdError::KillFailed(reason) => format!("error: failed to signal process: {}", reason),
+        }
+    }
+}
+
+pub fn handle_cmd(input: CompleteStr) -> nom::IResult<CompleteStr, Cmd, CmdError> {
+    parse_cmd(input)
+}
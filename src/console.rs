@@ -0,0 +1,38 @@
+// Scrollback buffer backing the debug/command console. Toggled with `/` and
+// fed command results/errors from `App::process_command`.
+pub struct Console {
+    pub visible: bool,
+    pub input: String,
+    pub lines: Vec<String>,
+}
+
+impl Console {
+    pub fn new() -> Console {
+        Console {
+            visible: false,
+            input: String::new(),
+            lines: vec![],
+        }
+    }
+
+    pub fn toggle_visibility(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    pub fn append_input(&mut self, c: char) {
+        self.input.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.input.pop();
+    }
+
+    // Takes the current input buffer, leaving it empty for the next command
+    pub fn clear_input(&mut self) -> String {
+        std::mem::replace(&mut self.input, String::new())
+    }
+
+    pub fn write(&mut self, line: String) {
+        self.lines.push(line);
+    }
+}
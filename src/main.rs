@@ -7,11 +7,13 @@ mod app;
 mod process;
 mod parser;
 mod cmd;
+mod options;
 
 use std::io;
 use std::io::Write;
 use std::thread;
-use std::sync::mpsc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
 use std::time::Duration;
 
 use tui::backend::TermionBackend;
@@ -24,6 +26,7 @@ use termion::screen::AlternateScreen;
 use termion::event::Key;
 
 use crate::system::System;
+use crate::options::Options;
 use crate::util::*;
 use crate::render::*;
 use crate::app::App;
@@ -38,23 +41,18 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     let backend = TermionBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
     let events = util::Events::new();
+    let options = Options::load();
+    let refresh_ms = Arc::new(AtomicU64::new(options.refresh_ms));
     let mut system = System::new(terminal.size()?.width);
-    let mut app = App {
-        mode: Mode::Main,
-        processes_sort_by: SortBy::CPU,
-        processes_sort_direction: SortDirection::DESC,
-        size: tui::layout::Rect::new(0, 0, 0, 0),
-        console: crate::console::Console::new(),
-        system: System::new(terminal.size()?.width),
-    };
+    let mut app = App::new(&options, Arc::clone(&refresh_ms), terminal.size()?.width);
 
     // Sets up separate thread for polling system resources
     let (system_tx, system_rx) = mpsc::channel();
     thread::spawn(move || {
         loop {
-            let system_update = system.update();
-            system_tx.send(system_update).unwrap();
-            thread::sleep(Duration::from_secs(1));
+            system.update();
+            system_tx.send(system.clone()).unwrap();
+            thread::sleep(Duration::from_millis(refresh_ms.load(Ordering::Relaxed)));
         }
     });
 
@@ -71,30 +69,53 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         // Defines areas for the cpu and memory graphs. Verically ordered
         let sparklines_constraints = vec![Constraint::Percentage(50); 2];
 
-        // Creates as many constraints as there are cpu cores. Verically ordered
-        let mut cpu_core_contraints = vec![Constraint::Length(3); app.system.cpu_num_cores];
+        // Creates one constraint per displayed cpu core (capped so the process/console
+        // panels below are never squeezed to zero height), or two when averaged. Verically ordered
+        let mut cpu_core_contraints = if app.per_core_cpu {
+            // Budget the rest of the layout at least this much room before
+            // handing any more height to per-core gauges.
+            let reserved_height = 5 + 3 + 6;
+            let max_rows = ((terminal.size()?.height as usize).saturating_sub(reserved_height) / 3).max(1);
+            let visible_cores = app.system.cpu_num_cores.min(max_rows);
+
+            let mut constraints = vec![Constraint::Length(3); visible_cores];
+            if visible_cores < app.system.cpu_num_cores {
+                constraints.push(Constraint::Length(3));
+            }
+            constraints
+        } else {
+            vec![Constraint::Length(3); 1]
+        };
         cpu_core_contraints.push(Constraint::Min(0));
 
         // Sets the height of the upper area to be tall enough for all the cpu cores and resizes the main view to make room for the console if it's showing. Verically ordered
         let main_view_constraints = if app.console.visible {
-            vec![Constraint::Length(((cpu_core_contraints.len() - 1) * 3) as u16), Constraint::Min(0), Constraint::Percentage(20), Constraint::Length(3)]
+            vec![Constraint::Length(((cpu_core_contraints.len() - 1) * 3) as u16), Constraint::Length(5), Constraint::Min(0), Constraint::Percentage(20), Constraint::Length(3)]
         } else {
-            vec![Constraint::Length(((cpu_core_contraints.len() - 1) * 3) as u16), Constraint::Min(0), Constraint::Percentage(0), Constraint::Length(3)]
+            vec![Constraint::Length(((cpu_core_contraints.len() - 1) * 3) as u16), Constraint::Length(5), Constraint::Min(0), Constraint::Percentage(0), Constraint::Length(3)]
         };
 
+        // Defines the temperature/disk/network row. Horizontally ordered
+        let sensors_row_constraints = vec![Constraint::Percentage(34), Constraint::Percentage(33), Constraint::Percentage(33)];
+
         // Define layouts for the different sections of the display
         let main_view_layout = define_layout(Direction::Vertical, &main_view_constraints, terminal.size()?);
         let system_overview_layout = define_layout(Direction::Horizontal, &system_overview_constrants, main_view_layout[0]);
         let sparklines_layout = define_layout(Direction::Vertical, &sparklines_constraints, system_overview_layout[1]);
         let cpu_cores_layout = define_layout(Direction::Vertical, &cpu_core_contraints, system_overview_layout[0]);
+        let sensors_row_layout = define_layout(Direction::Horizontal, &sensors_row_constraints, main_view_layout[1]);
+        let network_layout = define_layout(Direction::Vertical, &sparklines_constraints, sensors_row_layout[2]);
 
         // TODO: Implement lazy rendering
         terminal.draw(|mut f| {
             render_sparklines_layout(&mut f, &sparklines_layout, &app);
             render_cpu_cores_layout(&mut f, &cpu_cores_layout, &app);
-            render_processes_layout(&mut f, main_view_layout[1], &app);
-            render_console_layout(&mut f, main_view_layout[2], &app);
-            render_input_layout(&mut f, main_view_layout[3], &app);
+            render_temperatures_layout(&mut f, sensors_row_layout[0], &app);
+            render_disks_layout(&mut f, sensors_row_layout[1], &app);
+            render_network_layout(&mut f, &network_layout, &app);
+            render_processes_layout(&mut f, main_view_layout[2], &app);
+            render_console_layout(&mut f, main_view_layout[3], &app);
+            render_input_layout(&mut f, main_view_layout[4], &app);
         })?;
 
         // Positions cursor after user input
@@ -104,6 +125,10 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
             Goto(2 + app.console.input.len() as u16, app.size.height - 1)
         )?;
 
+        // Height of the process table's inner rows, used to page/scroll the selection.
+        // Must match render_processes_layout's viewport_height calculation (header + 2 borders).
+        let processes_viewport_height = main_view_layout[2].height.saturating_sub(3) as usize;
+
         terminal.show_cursor()?;
         if let util::Event::Input(input) = events.next()? {
             match input {
@@ -117,6 +142,14 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
                 // If enter was pressed, attempt to process current input as command
                 Key::Char('\n') => app.process_command(),
 
+                // Navigate the process table while the console isn't up
+                Key::Up if !app.console.visible => app.move_process_selection(-1, processes_viewport_height),
+                Key::Down if !app.console.visible => app.move_process_selection(1, processes_viewport_height),
+                Key::PageUp if !app.console.visible => app.move_process_selection(-(processes_viewport_height as i64), processes_viewport_height),
+                Key::PageDown if !app.console.visible => app.move_process_selection(processes_viewport_height as i64, processes_viewport_height),
+                Key::Home if !app.console.visible => app.select_first_process(),
+                Key::End if !app.console.visible => app.select_last_process(processes_viewport_height),
+
                 // Capture text input into the console
                 Key::Char(c) => app.console.append_input(c),
 
@@ -0,0 +1,151 @@
+extern crate toml;
+
+use std::fs;
+
+use crate::util::{SortBy, SortDirection, TemperatureUnit};
+
+// Floor for the refresh interval: below this, the polling thread's
+// per-tick work (a full sysinfo refresh, per-process /proc reads, a
+// System clone, and a channel send) can peg a core with no sleep at all.
+pub const MIN_REFRESH_MS: u64 = 50;
+
+// Fully resolved startup configuration: defaults, overridden by
+// `~/.config/ttm/config.toml`, overridden by CLI flags.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub refresh_ms: u64,
+    pub sort_by: SortBy,
+    pub sort_direction: SortDirection,
+    pub per_core_cpu: bool,
+    pub temperature_unit: TemperatureUnit,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            refresh_ms: 1000,
+            sort_by: SortBy::CPU,
+            sort_direction: SortDirection::DESC,
+            per_core_cpu: true,
+            temperature_unit: TemperatureUnit::Celsius,
+        }
+    }
+}
+
+impl Options {
+    pub fn load() -> Options {
+        let mut options = Options::default();
+        options.apply_config_file();
+        options.apply_cli_args(std::env::args().skip(1));
+        options
+    }
+
+    fn apply_config_file(&mut self) {
+        let path = match dirs_config_path() {
+            Some(path) => path,
+            None => return,
+        };
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(_) => return,
+        };
+
+        let parsed: toml::Value = match contents.parse() {
+            Ok(parsed) => parsed,
+            Err(_) => return,
+        };
+
+        // Reject non-positive values outright rather than casting them
+        // through `as u64`, which would wrap a negative value into a huge
+        // one and stall the refresh thread for years.
+        if let Some(value) = parsed.get("refresh_ms").and_then(|v| v.as_integer()) {
+            if value > 0 {
+                self.refresh_ms = (value as u64).max(MIN_REFRESH_MS);
+            }
+        }
+
+        if let Some(value) = parsed.get("sort_by").and_then(|v| v.as_str()) {
+            if let Some(sort_by) = parse_sort_by(value) {
+                self.sort_by = sort_by;
+            }
+        }
+
+        if let Some(value) = parsed.get("sort_direction").and_then(|v| v.as_str()) {
+            if let Some(sort_direction) = parse_sort_direction(value) {
+                self.sort_direction = sort_direction;
+            }
+        }
+
+        if let Some(value) = parsed.get("per_core_cpu").and_then(|v| v.as_bool()) {
+            self.per_core_cpu = value;
+        }
+
+        if let Some(value) = parsed.get("temperature_unit").and_then(|v| v.as_str()) {
+            if let Some(unit) = parse_temperature_unit(value) {
+                self.temperature_unit = unit;
+            }
+        }
+    }
+
+    fn apply_cli_args<I: Iterator<Item = String>>(&mut self, mut args: I) {
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--refresh-ms" => {
+                    if let Some(value) = args.next().and_then(|v| v.parse::<u64>().ok()) {
+                        self.refresh_ms = value.max(MIN_REFRESH_MS);
+                    }
+                }
+                "--sort-by" => {
+                    if let Some(sort_by) = args.next().as_deref().and_then(parse_sort_by) {
+                        self.sort_by = sort_by;
+                    }
+                }
+                "--sort-direction" => {
+                    if let Some(sort_direction) = args.next().as_deref().and_then(parse_sort_direction) {
+                        self.sort_direction = sort_direction;
+                    }
+                }
+                "--averaged-cpu" => self.per_core_cpu = false,
+                "--temp-unit" => {
+                    if let Some(unit) = args.next().as_deref().and_then(parse_temperature_unit) {
+                        self.temperature_unit = unit;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn parse_sort_by(value: &str) -> Option<SortBy> {
+    match value {
+        "cpu" => Some(SortBy::CPU),
+        "memory" => Some(SortBy::Memory),
+        "pid" => Some(SortBy::Pid),
+        "name" => Some(SortBy::Name),
+        _ => None,
+    }
+}
+
+fn parse_sort_direction(value: &str) -> Option<SortDirection> {
+    match value {
+        "asc" => Some(SortDirection::ASC),
+        "desc" => Some(SortDirection::DESC),
+        _ => None,
+    }
+}
+
+fn parse_temperature_unit(value: &str) -> Option<TemperatureUnit> {
+    match value {
+        "celsius" => Some(TemperatureUnit::Celsius),
+        "fahrenheit" => Some(TemperatureUnit::Fahrenheit),
+        "kelvin" => Some(TemperatureUnit::Kelvin),
+        _ => None,
+    }
+}
+
+fn dirs_config_path() -> Option<std::path::PathBuf> {
+    let home = std::env::var("HOME").ok()?;
+    Some(std::path::Path::new(&home).join(".config/ttm/config.toml"))
+}
@@ -0,0 +1,64 @@
+use nom::types::CompleteStr;
+use nom::digit;
+
+use crate::cmd::{Cmd, CmdError};
+use crate::util::TemperatureUnit;
+
+// Grammar: `kill <pid> [signal]`
+named!(pub parse_kill<CompleteStr, Cmd, CmdError>,
+    fix_error!(CmdError, do_parse!(
+        tag!("kill") >>
+        call!(nom::sp) >>
+        pid: map_res!(digit, |s: CompleteStr| s.0.parse::<i32>()) >>
+        signal: opt!(preceded!(call!(nom::sp), map_res!(digit, |s: CompleteStr| s.0.parse::<i32>()))) >>
+        (Cmd::KillProcess(pid, signal))
+    ))
+);
+
+// Grammar: `temp celsius|fahrenheit|kelvin`
+named!(pub parse_temp<CompleteStr, Cmd, CmdError>,
+    fix_error!(CmdError, do_parse!(
+        tag!("temp") >>
+        call!(nom::sp) >>
+        unit: alt!(
+            tag!("celsius") => { |_| TemperatureUnit::Celsius } |
+            tag!("fahrenheit") => { |_| TemperatureUnit::Fahrenheit } |
+            tag!("kelvin") => { |_| TemperatureUnit::Kelvin }
+        ) >>
+        (Cmd::SetTemperatureUnit(unit))
+    ))
+);
+
+// Grammar: `set refresh <ms>`
+named!(pub parse_set_refresh<CompleteStr, Cmd, CmdError>,
+    fix_error!(CmdError, do_parse!(
+        tag!("set") >>
+        call!(nom::sp) >>
+        tag!("refresh") >>
+        call!(nom::sp) >>
+        ms: map_res!(digit, |s: CompleteStr| s.0.parse::<u64>()) >>
+        (Cmd::SetRefresh(ms))
+    ))
+);
+
+// Grammar: `cpu per-core|averaged`
+named!(pub parse_cpu_mode<CompleteStr, Cmd, CmdError>,
+    fix_error!(CmdError, do_parse!(
+        tag!("cpu") >>
+        call!(nom::sp) >>
+        per_core: alt!(
+            tag!("per-core") => { |_| true } |
+            tag!("averaged") => { |_| false }
+        ) >>
+        (Cmd::SetCpuMode(per_core))
+    ))
+);
+
+named!(pub parse_cmd<CompleteStr, Cmd, CmdError>,
+    alt!(
+        parse_kill |
+        parse_temp |
+        parse_set_refresh |
+        parse_cpu_mode
+    )
+);
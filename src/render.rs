@@ -0,0 +1,184 @@
+use tui::backend::Backend;
+use tui::layout::Rect;
+use tui::style::{Color, Style};
+use tui::widgets::{Block, Borders, Gauge, Paragraph, Row, Sparkline, Table, Text, Widget};
+use tui::Frame;
+
+use crate::app::App;
+
+pub fn render_sparklines_layout<B: Backend>(f: &mut Frame<B>, layout: &[Rect], app: &App) {
+    Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title("CPU History"))
+        .data(&app.system.cpu_usage_history)
+        .style(Style::default().fg(Color::Green))
+        .render(f, layout[0]);
+
+    Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title("Memory History"))
+        .data(&app.system.mem_usage_history)
+        .style(Style::default().fg(Color::Cyan))
+        .render(f, layout[1]);
+}
+
+pub fn render_cpu_cores_layout<B: Backend>(f: &mut Frame<B>, layout: &[Rect], app: &App) {
+    if !app.per_core_cpu {
+        // The CPU history sparkline is already shown by render_sparklines_layout;
+        // this panel only needs the current averaged reading.
+        let num_cores = app.system.cpu_core_usages.len().max(1);
+        let average = (app.system.cpu_core_usages.iter().map(|&u| u as u32).sum::<u32>() / num_cores as u32) as u16;
+
+        Gauge::default()
+            .block(Block::default().borders(Borders::ALL).title("CPU (avg)"))
+            .style(Style::default().fg(Color::Yellow))
+            .percent(average)
+            .render(f, layout[0]);
+
+        return;
+    }
+
+    let num_cores = app.system.cpu_core_usages.len();
+
+    // Reserve the final slot for `Min(0)`; if there isn't room for every
+    // core, reserve one more for the "+N more" summary so the process and
+    // console panels are never squeezed to zero height. A count exactly one
+    // over the cap fills the summary row's rect with that last core's gauge.
+    let gauge_capacity = if num_cores > layout.len().saturating_sub(1) {
+        layout.len().saturating_sub(2)
+    } else {
+        layout.len().saturating_sub(1)
+    };
+
+    for (i, usage) in app.system.cpu_core_usages.iter().enumerate().take(gauge_capacity) {
+        Gauge::default()
+            .block(Block::default().borders(Borders::ALL).title(&format!("Core {}", i)))
+            .style(Style::default().fg(Color::Yellow))
+            .percent(*usage as u16)
+            .render(f, layout[i]);
+    }
+
+    if num_cores > gauge_capacity {
+        let remaining = &app.system.cpu_core_usages[gauge_capacity..];
+        let remaining_avg = remaining.iter().map(|&u| u as u32).sum::<u32>() / remaining.len() as u32;
+        let text = [Text::raw(format!("+{} more cores (avg {}%)", remaining.len(), remaining_avg))];
+
+        Paragraph::new(text.iter())
+            .block(Block::default().borders(Borders::ALL))
+            .render(f, layout[gauge_capacity]);
+    }
+}
+
+pub fn render_processes_layout<B: Backend>(f: &mut Frame<B>, area: Rect, app: &App) {
+    let processes = crate::system::sorted_processes(&app.system.processes, app.processes_sort_by, app.processes_sort_direction);
+    let viewport_height = area.height.saturating_sub(3) as usize;
+    let offset = app.process_scroll_offset.min(processes.len().saturating_sub(1));
+
+    // Re-derive the highlighted row from the selected pid against this
+    // frame's freshly sorted list, since table position shifts every tick
+    // but the pid doesn't. Fall back to the scroll position if the process
+    // has since exited.
+    let selected_index = app.selected_pid
+        .and_then(|pid| processes.iter().position(|p| p.pid == pid))
+        .unwrap_or(offset)
+        .min(processes.len().saturating_sub(1));
+
+    let rows = processes
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(viewport_height.max(1))
+        .map(|(i, p)| {
+            let cells = vec![
+                p.pid.to_string(),
+                p.name.clone(),
+                format!("{:.1}%", p.cpu_usage),
+                format!("{} KB", p.memory),
+            ];
+
+            if i == selected_index {
+                Row::StyledData(
+                    cells.into_iter(),
+                    Style::default().fg(Color::Black).bg(Color::White),
+                )
+            } else {
+                Row::Data(cells.into_iter())
+            }
+        });
+
+    Table::new(["PID", "Name", "CPU%", "Memory"].into_iter(), rows)
+        .block(Block::default().borders(Borders::ALL).title("Processes"))
+        .widths(&[10, 30, 10, 15])
+        .render(f, area);
+}
+
+pub fn render_temperatures_layout<B: Backend>(f: &mut Frame<B>, area: Rect, app: &App) {
+    let rows = app.system.temperatures.iter().map(|(label, celsius)| {
+        Row::Data(
+            vec![
+                label.clone(),
+                format!("{:.1}°{}", app.temperature_unit.convert(*celsius), app.temperature_unit.label()),
+            ]
+            .into_iter(),
+        )
+    });
+
+    Table::new(["Sensor", "Temp"].into_iter(), rows)
+        .block(Block::default().borders(Borders::ALL).title("Temperatures"))
+        .widths(&[30, 15])
+        .render(f, area);
+}
+
+fn bytes_to_gb(bytes: u64) -> f64 {
+    bytes as f64 / 1024.0 / 1024.0 / 1024.0
+}
+
+pub fn render_disks_layout<B: Backend>(f: &mut Frame<B>, area: Rect, app: &App) {
+    let rows = app.system.disks.iter().map(|d| {
+        Row::Data(
+            vec![
+                d.mount_point.clone(),
+                format!("{:.1} / {:.1} GB", bytes_to_gb(d.used), bytes_to_gb(d.total)),
+            ]
+            .into_iter(),
+        )
+    });
+
+    Table::new(["Mount", "Used / Total"].into_iter(), rows)
+        .block(Block::default().borders(Borders::ALL).title("Disks"))
+        .widths(&[20, 25])
+        .render(f, area);
+}
+
+pub fn render_network_layout<B: Backend>(f: &mut Frame<B>, layout: &[Rect], app: &App) {
+    Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title("Network RX/s"))
+        .data(&app.system.net_rx_history)
+        .style(Style::default().fg(Color::Magenta))
+        .render(f, layout[0]);
+
+    Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title("Network TX/s"))
+        .data(&app.system.net_tx_history)
+        .style(Style::default().fg(Color::Blue))
+        .render(f, layout[1]);
+}
+
+pub fn render_console_layout<B: Backend>(f: &mut Frame<B>, area: Rect, app: &App) {
+    let text: Vec<Text> = app
+        .console
+        .lines
+        .iter()
+        .map(|l| Text::raw(format!("{}\n", l)))
+        .collect();
+
+    Paragraph::new(text.iter())
+        .block(Block::default().borders(Borders::ALL).title("Console"))
+        .render(f, area);
+}
+
+pub fn render_input_layout<B: Backend>(f: &mut Frame<B>, area: Rect, app: &App) {
+    let text = [Text::raw(format!("> {}", app.console.input))];
+
+    Paragraph::new(text.iter())
+        .block(Block::default().borders(Borders::ALL))
+        .render(f, area);
+}
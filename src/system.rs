@@ -1,7 +1,39 @@
 extern crate sysinfo;
 
-use sysinfo::{SystemExt, ProcessorExt};
+use std::collections::HashMap;
 
+use sysinfo::{ComponentExt, DiskExt, NetworkExt, ProcessExt, ProcessorExt, SystemExt};
+
+use crate::process::CpuSampler;
+use crate::util::{SortBy, SortDirection};
+
+// A single row in the process table
+#[derive(Debug, Clone)]
+pub struct ProcessInfo {
+    pub pid: i32,
+    pub name: String,
+    pub cpu_usage: f32,
+    pub memory: u64,
+}
+
+// A single row in the disk table
+#[derive(Debug, Clone)]
+pub struct DiskInfo {
+    pub mount_point: String,
+    pub total: u64,
+    pub used: u64,
+}
+
+// A single row in the network table, with throughput already converted from
+// a cumulative byte counter to a per-second rate for the current tick
+#[derive(Debug, Clone)]
+pub struct NetworkInfo {
+    pub interface: String,
+    pub rx_rate: u64,
+    pub tx_rate: u64,
+}
+
+#[derive(Clone)]
 pub struct System {
     system: sysinfo::System,
     pub cpu_usage_history: Vec<u64>,
@@ -11,11 +43,21 @@ pub struct System {
     pub mem_free: u64,
     pub mem_used: u64,
     pub mem_usage_history: Vec<u64>,
-    pub cpu_core_usages: Vec<u16>
+    pub cpu_core_usages: Vec<u16>,
+    pub processes: Vec<ProcessInfo>,
+    // Component label paired with its reading, in Celsius
+    pub temperatures: Vec<(String, f32)>,
+    pub disks: Vec<DiskInfo>,
+    pub networks: Vec<NetworkInfo>,
+    pub net_rx_history: Vec<u64>,
+    pub net_tx_history: Vec<u64>,
+    cpu_sampler: CpuSampler,
+    prev_net_bytes: HashMap<String, (u64, u64)>,
 }
 
 impl System {
-    pub fn new(system: sysinfo::System, initial_size: u16) -> System {
+    pub fn new(initial_size: u16) -> System {
+        let system = sysinfo::System::new();
         let history_width = initial_size / 2;
 
         // Overall CPU usage
@@ -35,7 +77,15 @@ impl System {
             mem_free: 0,
             mem_used: 0,
             mem_usage_history,
-            cpu_core_usages: vec![]
+            cpu_core_usages: vec![],
+            processes: vec![],
+            temperatures: vec![],
+            disks: vec![],
+            networks: vec![],
+            net_rx_history: vec![0; history_width as usize],
+            net_tx_history: vec![0; history_width as usize],
+            cpu_sampler: CpuSampler::new(),
+            prev_net_bytes: HashMap::new(),
         }
     }
 
@@ -59,5 +109,103 @@ impl System {
             .skip(1)
             .map(|p| (p.get_cpu_usage() * 100.0).round() as u16)
             .collect();
+
+        // Tracked processes, used by the process table and the `kill` command
+        // to validate a pid before signalling it.
+        self.processes = self.system.get_process_list()
+            .values()
+            .map(|p| ProcessInfo {
+                pid: p.pid(),
+                name: p.name().to_string(),
+                cpu_usage: p.cpu_usage(),
+                memory: p.memory(),
+            })
+            .collect();
+
+        // Replaces sysinfo's coarse per-process figure with a `top`-style
+        // jiffy-delta sample; a no-op on platforms without `/proc`.
+        self.cpu_sampler.sample(&mut self.processes, self.cpu_num_cores);
+
+        // Sensor readings, always collected in Celsius; the temperature
+        // widget converts to the user's chosen unit at render time.
+        self.temperatures = self.system.get_components_list()
+            .iter()
+            .map(|c| (c.get_label().to_string(), c.get_temperature()))
+            .collect();
+
+        // Mounted filesystems
+        self.disks = self.system.get_disks()
+            .iter()
+            .map(|d| DiskInfo {
+                mount_point: d.get_mount_point().to_string_lossy().to_string(),
+                total: d.get_total_space(),
+                used: d.get_total_space() - d.get_available_space(),
+            })
+            .collect();
+
+        // Per-interface throughput: diff this tick's cumulative counters
+        // (get_total_*, not get_received()/get_transmitted(), which are
+        // already a per-tick delta) against the last tick's.
+        let mut seen = HashMap::new();
+        let mut total_rx_rate: u64 = 0;
+        let mut total_tx_rate: u64 = 0;
+
+        self.networks = self.system.get_networks()
+            .iter()
+            .map(|(interface, data)| {
+                let rx_bytes = data.get_total_received();
+                let tx_bytes = data.get_total_transmitted();
+                let (prev_rx, prev_tx) = *self.prev_net_bytes.get(interface).unwrap_or(&(rx_bytes, tx_bytes));
+
+                let rx_rate = rx_bytes.saturating_sub(prev_rx);
+                let tx_rate = tx_bytes.saturating_sub(prev_tx);
+
+                total_rx_rate += rx_rate;
+                total_tx_rate += tx_rate;
+                seen.insert(interface.clone(), (rx_bytes, tx_bytes));
+
+                NetworkInfo {
+                    interface: interface.clone(),
+                    rx_rate,
+                    tx_rate,
+                }
+            })
+            .collect();
+
+        self.prev_net_bytes = seen;
+
+        self.net_rx_history.push(total_rx_rate);
+        self.net_rx_history.remove(0);
+        self.net_tx_history.push(total_tx_rate);
+        self.net_tx_history.remove(0);
     }
+
+    // Used by the `kill` command to make sure it isn't signalling a pid we
+    // haven't actually observed.
+    pub fn has_process(&self, pid: i32) -> bool {
+        self.processes.iter().any(|p| p.pid == pid)
+    }
+}
+
+// Shared by the renderer (to decide what to draw) and `App` (to track the
+// selected row by pid rather than table position), so the two never disagree
+// about what "row 3" means.
+pub fn sorted_processes(processes: &[ProcessInfo], sort_by: SortBy, direction: SortDirection) -> Vec<ProcessInfo> {
+    let mut processes = processes.to_vec();
+
+    processes.sort_by(|a, b| {
+        let ordering = match sort_by {
+            SortBy::CPU => a.cpu_usage.partial_cmp(&b.cpu_usage).unwrap(),
+            SortBy::Memory => a.memory.cmp(&b.memory),
+            SortBy::Pid => a.pid.cmp(&b.pid),
+            SortBy::Name => a.name.cmp(&b.name),
+        };
+
+        match direction {
+            SortDirection::ASC => ordering,
+            SortDirection::DESC => ordering.reverse(),
+        }
+    });
+
+    processes
 }
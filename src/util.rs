@@ -0,0 +1,97 @@
+use std::io;
+use std::sync::mpsc;
+use std::thread;
+
+use termion::event::Key;
+use termion::input::TermRead;
+use tui::layout::{Constraint, Direction, Layout, Rect};
+
+// The screen the app is currently displaying. Kept as an enum so new screens
+// (help, details, ...) can be added without touching the render call sites.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    Main,
+}
+
+// Column the process table is currently ordered by
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortBy {
+    CPU,
+    Memory,
+    Pid,
+    Name,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortDirection {
+    ASC,
+    DESC,
+}
+
+// Unit the temperature widget renders readings in. Sensors are always
+// collected in Celsius; conversion happens at render time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+impl TemperatureUnit {
+    pub fn convert(&self, celsius: f32) -> f32 {
+        match self {
+            TemperatureUnit::Celsius => celsius,
+            TemperatureUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+            TemperatureUnit::Kelvin => celsius + 273.15,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "C",
+            TemperatureUnit::Fahrenheit => "F",
+            TemperatureUnit::Kelvin => "K",
+        }
+    }
+}
+
+pub enum Event<I> {
+    Input(I),
+}
+
+// Reads keys off of stdin on a background thread and forwards them over a channel;
+// `next` blocks until the next key arrives.
+pub struct Events {
+    rx: mpsc::Receiver<Event<Key>>,
+}
+
+impl Events {
+    pub fn new() -> Events {
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let stdin = io::stdin();
+            for key in stdin.keys() {
+                if let Ok(key) = key {
+                    if tx.send(Event::Input(key)).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        Events { rx }
+    }
+
+    pub fn next(&self) -> Result<Event<Key>, mpsc::RecvError> {
+        self.rx.recv()
+    }
+}
+
+// Splits `area` into `constraints.len()` chunks along `direction`
+pub fn define_layout(direction: Direction, constraints: &[Constraint], area: Rect) -> Vec<Rect> {
+    Layout::default()
+        .direction(direction)
+        .constraints(constraints.to_vec())
+        .split(area)
+}